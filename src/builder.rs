@@ -0,0 +1,76 @@
+//! Fluent builder for assembling sequences of `Instruction`s.
+
+use crate::Instruction;
+use alloc::vec::Vec;
+
+/// Accumulates `Instruction`s into a program, exposing one chainable method per opcode.
+///
+/// The per-opcode methods (`add`, `jmp`, ...) are generated by `impl_opcodes!` from the same
+/// free-function constructors used elsewhere in the crate, so
+/// `builder.add(ra, rb, rc).jmp(ra)` mirrors calling the free functions directly, without the
+/// caller constructing and pushing each `Instruction` by hand.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct InstructionBuilder {
+    instructions: Vec<Instruction>,
+}
+
+impl InstructionBuilder {
+    /// Create an empty builder.
+    pub fn new() -> Self {
+        Self { instructions: Vec::new() }
+    }
+
+    /// The word offset, in bytes, that the next pushed instruction will occupy.
+    ///
+    /// Useful for computing the target of a jump to an instruction not yet emitted.
+    pub fn next_offset(&self) -> usize {
+        self.instructions.len() * 4
+    }
+
+    pub(crate) fn push(&mut self, instruction: Instruction) {
+        self.instructions.push(instruction);
+    }
+
+    /// Finalize the builder into its accumulated `Instruction`s.
+    pub fn finish(self) -> Vec<Instruction> {
+        self.instructions
+    }
+
+    /// Finalize the builder into its packed big-endian byte encoding.
+    pub fn finish_bytes(self) -> Vec<u8> {
+        self.instructions.into_iter().flat_map(<[u8; 4]>::from).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RegId;
+
+    #[test]
+    fn methods_chain_and_finish_in_push_order() {
+        let mut builder = InstructionBuilder::new();
+        builder.add(RegId::new(1), RegId::new(2), RegId::new(3)).ret(RegId::new(1));
+        let instructions = builder.finish();
+        assert_eq!(instructions.len(), 2);
+        assert!(instructions[0].opcode() == crate::Opcode::Add);
+        assert!(instructions[1].opcode() == crate::Opcode::Ret);
+    }
+
+    #[test]
+    fn next_offset_tracks_the_number_of_pushed_instructions() {
+        let mut builder = InstructionBuilder::new();
+        assert_eq!(builder.next_offset(), 0);
+        builder.noop();
+        assert_eq!(builder.next_offset(), 4);
+        builder.noop();
+        assert_eq!(builder.next_offset(), 8);
+    }
+
+    #[test]
+    fn finish_bytes_packs_instructions_big_endian() {
+        let mut builder = InstructionBuilder::new();
+        builder.ret(RegId::new(1));
+        assert_eq!(builder.finish_bytes(), alloc::vec![0x43, 4, 0, 0]);
+    }
+}
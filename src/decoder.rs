@@ -0,0 +1,119 @@
+//! Streaming decoding of byte buffers into `Instruction`s.
+
+use crate::{Instruction, InvalidOpcode};
+use core::convert::TryFrom;
+
+/// An error produced while decoding a byte stream into `Instruction`s.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DecodeError {
+    /// The 4-byte word did not correspond to a known `Opcode`.
+    InvalidOpcode(InvalidOpcode),
+    /// The stream ended with a partial word (its length was not a multiple of 4).
+    TrailingBytes,
+}
+
+impl From<InvalidOpcode> for DecodeError {
+    fn from(err: InvalidOpcode) -> Self {
+        Self::InvalidOpcode(err)
+    }
+}
+
+/// An iterator that decodes a stream of bytes into `Instruction`s, one 4-byte big-endian word at
+/// a time.
+///
+/// Every item, `Ok` or `Err`, is paired with the byte offset at which its word began, so a
+/// `DecodeError` can still be reported as "bad opcode at offset N". Decoding continues past an
+/// `InvalidOpcode` so that tooling can disassemble partially-valid blobs; a trailing partial word
+/// (length not a multiple of 4) yields a single `DecodeError::TrailingBytes` and ends the
+/// iterator.
+pub struct Instructions<I> {
+    bytes: I,
+    offset: usize,
+    done: bool,
+}
+
+impl<I> Instructions<I> {
+    fn new(bytes: I) -> Self {
+        Self { bytes, offset: 0, done: false }
+    }
+}
+
+/// Decode `Instruction`s from a byte slice.
+pub fn from_bytes(bytes: &[u8]) -> Instructions<core::iter::Copied<core::slice::Iter<'_, u8>>> {
+    Instructions::new(bytes.iter().copied())
+}
+
+/// Decode `Instruction`s from any iterator of bytes.
+pub fn from_iter<I: Iterator<Item = u8>>(bytes: I) -> Instructions<I> {
+    Instructions::new(bytes)
+}
+
+impl<I: Iterator<Item = u8>> Iterator for Instructions<I> {
+    type Item = Result<(usize, Instruction), (usize, DecodeError)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let start = self.offset;
+        let mut word = [0u8; 4];
+        let mut len = 0;
+        for slot in word.iter_mut() {
+            match self.bytes.next() {
+                Some(byte) => {
+                    *slot = byte;
+                    len += 1;
+                }
+                None => break,
+            }
+        }
+
+        if len == 0 {
+            self.done = true;
+            return None;
+        }
+        self.offset += len;
+        if len < 4 {
+            self.done = true;
+            return Some(Err((start, DecodeError::TrailingBytes)));
+        }
+
+        Some(Instruction::try_from(word).map(|inst| (start, inst)).map_err(|err| (start, DecodeError::from(err))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trailing_bytes_reports_their_offset() {
+        let bytes = [0u8, 0, 0]; // one byte short of a full word
+        let mut decoded = from_bytes(&bytes);
+        assert_eq!(decoded.next(), Some(Err((0, DecodeError::TrailingBytes))));
+        assert_eq!(decoded.next(), None);
+    }
+
+    #[test]
+    fn continues_past_an_invalid_opcode() {
+        // 0xfe is not assigned to any opcode; the second word is all zero, which decodes to
+        // `Noop` (opcode 0x00).
+        let bytes = [0xfeu8, 0, 0, 0, 0, 0, 0, 0];
+        let mut decoded = from_bytes(&bytes);
+        match decoded.next() {
+            Some(Err((0, DecodeError::InvalidOpcode(_)))) => {}
+            other => panic!("expected an InvalidOpcode at offset 0, got {other:?}"),
+        }
+        match decoded.next() {
+            Some(Ok((4, _))) => {}
+            other => panic!("expected a decoded instruction at offset 4, got {other:?}"),
+        }
+        assert_eq!(decoded.next(), None);
+    }
+
+    #[test]
+    fn empty_input_yields_nothing() {
+        assert_eq!(from_bytes(&[]).next(), None);
+    }
+}
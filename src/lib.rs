@@ -0,0 +1,65 @@
+//! Instruction types for the FuelVM.
+//!
+//! This crate defines the instruction set architecture encoding used by the FuelVM in terms of
+//! typed structures and conversions, but it does not implement the interpreter itself.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+mod types;
+use types::*;
+
+#[macro_use]
+mod macros;
+
+mod builder;
+mod decoder;
+
+pub use builder::InstructionBuilder;
+pub use decoder::{from_bytes, from_iter, DecodeError, Instructions};
+pub use macros::{InstructionGroup, ParseError, Regs};
+pub use types::{Imm12, Imm18, Imm24, InvalidOpcode, RegId};
+
+impl_opcodes! {
+    "Adds two registers." 0x10 Add add Arithmetic [Def Use Use] [RegId RegId RegId]
+    "Subtracts two registers." 0x11 Sub sub Arithmetic [Def Use Use] [RegId RegId RegId]
+    "Multiplies two registers." 0x12 Mul mul Arithmetic [Def Use Use] [RegId RegId RegId]
+    "Divides two registers." 0x13 Div div Arithmetic [Def Use Use] [RegId RegId RegId]
+    "Bitwise ANDs two registers." 0x14 And and_ Arithmetic [Def Use Use] [RegId RegId RegId]
+    "Bitwise ORs two registers." 0x15 Or or_ Arithmetic [Def Use Use] [RegId RegId RegId]
+    "Bitwise XORs two registers." 0x16 Xor xor Arithmetic [Def Use Use] [RegId RegId RegId]
+    "Compares two registers for equality." 0x17 Eq eq Arithmetic [Def Use Use] [RegId RegId RegId]
+    "Compares two registers for less-than." 0x18 Lt lt Arithmetic [Def Use Use] [RegId RegId RegId]
+    "Divides two registers, storing the quotient and remainder." 0x19 Dvm dvm Arithmetic [Def Def Use Use] [RegId RegId RegId RegId]
+
+    "Adds a register and an immediate." 0x20 Addi addi Arithmetic [Def Use] [RegId RegId Imm12]
+    "Subtracts an immediate from a register." 0x21 Subi subi Arithmetic [Def Use] [RegId RegId Imm12]
+    "Bitwise ANDs a register and an immediate." 0x22 Andi andi Arithmetic [Def Use] [RegId RegId Imm12]
+    "Bitwise ORs a register and an immediate." 0x23 Ori ori Arithmetic [Def Use] [RegId RegId Imm12]
+    "Bitwise XORs a register and an immediate." 0x24 Xori xori Arithmetic [Def Use] [RegId RegId Imm12]
+
+    "Bitwise NOT of a register." 0x25 Not not_ Arithmetic [Def Use] [RegId RegId]
+    "Copies a register's value." 0x26 Move move_ Arithmetic [Def Use] [RegId RegId]
+    "Loads an 18-bit immediate into a register." 0x27 Movi movi Arithmetic [Def] [RegId Imm18]
+
+    "Loads a byte from memory." 0x30 Lb lb Memory [Def Use] [RegId RegId Imm12]
+    "Loads a word from memory." 0x31 Lw lw Memory [Def Use] [RegId RegId Imm12]
+    "Stores a byte to memory." 0x32 Sb sb Memory [Use Use] [RegId RegId Imm12]
+    "Stores a word to memory." 0x33 Sw sw Memory [Use Use] [RegId RegId Imm12]
+    "Copies a block of memory." 0x34 Mcp mcp Memory [Use Use Use] [RegId RegId RegId]
+
+    "Jumps to an immediate absolute address." 0x40 Ji ji Jump [] [Imm24]
+    "Jumps to an immediate address if two registers are not equal." 0x41 Jnei jnei Jump [Use Use] [RegId RegId Imm12]
+    "Jumps to an address held in a register." 0x42 Jmp jmp Jump [Use] [RegId]
+    "Returns from the current context." 0x43 Ret ret Return [Use] [RegId]
+    "Calls into another contract." 0x44 Call call Call [Use Use Use Use] [RegId RegId RegId RegId]
+
+    "Gets the balance of a contract for an asset." 0x50 Bal bal Contract [Def Use Use] [RegId RegId RegId]
+    "Reads a word from contract storage." 0x51 Srw srw Contract [Def Use] [RegId RegId]
+
+    "Computes a SHA-256 hash over a range of memory." 0x60 S256 s256 Crypto [Use Use Use] [RegId RegId RegId]
+    "Recovers a public key from a secp256r1 signature." 0x61 Ecr1 ecr1 Crypto [Use Use Use] [RegId RegId RegId]
+
+    "Performs no operation." 0x00 Noop noop Other [] []
+}
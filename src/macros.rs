@@ -1,3 +1,96 @@
+use crate::types::{Imm12, Imm18, Imm24, RegId};
+
+/// The register operands of an `Instruction`, in `ra, rb, rc, rd` order.
+///
+/// A field is `None` when the instruction's layout does not carry that operand.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Regs {
+    /// Register operand A, if present.
+    pub ra: Option<RegId>,
+    /// Register operand B, if present.
+    pub rb: Option<RegId>,
+    /// Register operand C, if present.
+    pub rc: Option<RegId>,
+    /// Register operand D, if present.
+    pub rd: Option<RegId>,
+}
+
+/// The broad category an opcode belongs to, for control-flow and dataflow analysis.
+///
+/// Each opcode declares its group once as part of its `impl_opcodes!` entry; `Instruction::group`
+/// returns it without the caller needing to match every variant by hand.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InstructionGroup {
+    /// Arithmetic and logic operations.
+    Arithmetic,
+    /// Memory load and store operations.
+    Memory,
+    /// Unconditional or conditional jumps to a fixed or computed target.
+    Jump,
+    /// Calls into another execution context.
+    Call,
+    /// Returns from a call.
+    Return,
+    /// Contract-specific operations such as state, balance, or code access.
+    Contract,
+    /// Cryptographic operations such as hashing or signature verification.
+    Crypto,
+    /// Anything not covered by the other groups.
+    Other,
+}
+
+/// An error produced while parsing a line of assembly text back into an `Instruction`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input was empty.
+    Empty,
+    /// The mnemonic did not match any known opcode.
+    UnknownMnemonic,
+    /// An operand was missing or of the wrong kind, or there were extra trailing tokens.
+    InvalidOperand,
+    /// A numeric literal did not fit in the destination immediate's width.
+    ImmediateOverflow,
+}
+
+pub(crate) fn parse_register(tok: Option<&str>) -> Result<RegId, ParseError> {
+    let tok = tok.ok_or(ParseError::InvalidOperand)?;
+    let n: u8 = tok.strip_prefix('$').unwrap_or(tok).parse().map_err(|_| ParseError::InvalidOperand)?;
+    Ok(RegId::new(n))
+}
+
+pub(crate) fn parse_imm12(tok: Option<&str>) -> Result<Imm12, ParseError> {
+    let n: u32 = tok.ok_or(ParseError::InvalidOperand)?.parse().map_err(|_| ParseError::InvalidOperand)?;
+    if n > 0xfff {
+        return Err(ParseError::ImmediateOverflow);
+    }
+    Ok(Imm12::new(n as u16))
+}
+
+pub(crate) fn parse_imm18(tok: Option<&str>) -> Result<Imm18, ParseError> {
+    let n: u32 = tok.ok_or(ParseError::InvalidOperand)?.parse().map_err(|_| ParseError::InvalidOperand)?;
+    if n > 0x3ffff {
+        return Err(ParseError::ImmediateOverflow);
+    }
+    Ok(Imm18::new(n))
+}
+
+pub(crate) fn parse_imm24(tok: Option<&str>) -> Result<Imm24, ParseError> {
+    let n: u32 = tok.ok_or(ParseError::InvalidOperand)?.parse().map_err(|_| ParseError::InvalidOperand)?;
+    if n > 0xffffff {
+        return Err(ParseError::ImmediateOverflow);
+    }
+    Ok(Imm24::new(n))
+}
+
+pub(crate) fn parse_end(tokens: &mut core::str::SplitAsciiWhitespace) -> Result<(), ParseError> {
+    match tokens.next() {
+        None => Ok(()),
+        Some(_) => Err(ParseError::InvalidOperand),
+    }
+}
+
 /// Generates the following:
 ///
 /// - A unique type for each opcode instruction type.
@@ -6,7 +99,7 @@
 /// - An enum over all possible instructions.
 macro_rules! impl_opcodes {
     // Recursively declares a unique struct for each opcode.
-    (decl_op_struct $doc:literal $ix:literal $Op:ident $op:ident [$($field:ident)*] $($rest:tt)*) => {
+    (decl_op_struct $doc:literal $ix:literal $Op:ident $op:ident $group:ident [$($role:ident)*] [$($field:ident)*] $($rest:tt)*) => {
         #[doc = $doc]
         #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
         #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -16,7 +109,7 @@ macro_rules! impl_opcodes {
     (decl_op_struct) => {};
 
     // Define the `OpcodeRepr` enum.
-    (decl_opcode_enum $($doc:literal $ix:literal $Op:ident $op:ident [$($field:ident)*])*) => {
+    (decl_opcode_enum $($doc:literal $ix:literal $Op:ident $op:ident $group:ident [$($role:ident)*] [$($field:ident)*])*) => {
         /// Solely the opcode portion of an instruction represented as a single byte.
         #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
         #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -30,7 +123,7 @@ macro_rules! impl_opcodes {
     };
 
     // Define the `Opcode` enum.
-    (decl_instruction_enum $($doc:literal $ix:literal $Op:ident $op:ident [$($field:ident)*])*) => {
+    (decl_instruction_enum $($doc:literal $ix:literal $Op:ident $op:ident $group:ident [$($role:ident)*] [$($field:ident)*])*) => {
         /// Representation of a single instruction for the interpreter.
         ///
         /// The opcode is represented in the tag (variant), or may be retrieved in the form of an
@@ -193,6 +286,309 @@ macro_rules! impl_opcodes {
     };
     (impl_op_unpack []) => {};
 
+    // Build a `Regs` value from an op binding, based on its field layout.
+    (impl_op_regs $op:ident [RegId]) => {
+        Regs { ra: Some($op.ra()), rb: None, rc: None, rd: None }
+    };
+    (impl_op_regs $op:ident [RegId RegId]) => {
+        Regs { ra: Some($op.ra()), rb: Some($op.rb()), rc: None, rd: None }
+    };
+    (impl_op_regs $op:ident [RegId RegId RegId]) => {
+        Regs { ra: Some($op.ra()), rb: Some($op.rb()), rc: Some($op.rc()), rd: None }
+    };
+    (impl_op_regs $op:ident [RegId RegId RegId RegId]) => {
+        Regs { ra: Some($op.ra()), rb: Some($op.rb()), rc: Some($op.rc()), rd: Some($op.rd()) }
+    };
+    (impl_op_regs $op:ident [RegId RegId Imm12]) => {
+        Regs { ra: Some($op.ra()), rb: Some($op.rb()), rc: None, rd: None }
+    };
+    (impl_op_regs $op:ident [RegId Imm18]) => {
+        Regs { ra: Some($op.ra()), rb: None, rc: None, rd: None }
+    };
+    (impl_op_regs $op:ident [Imm24]) => {{
+        let _ = $op;
+        Regs { ra: None, rb: None, rc: None, rd: None }
+    }};
+    (impl_op_regs $op:ident []) => {{
+        let _ = $op;
+        Regs { ra: None, rb: None, rc: None, rd: None }
+    }};
+
+    // Widen an op binding's immediate field (if any) into an `Option<u32>`, based on its field
+    // layout.
+    (impl_op_immediate $op:ident [RegId]) => {{ let _ = $op; None }};
+    (impl_op_immediate $op:ident [RegId RegId]) => {{ let _ = $op; None }};
+    (impl_op_immediate $op:ident [RegId RegId RegId]) => {{ let _ = $op; None }};
+    (impl_op_immediate $op:ident [RegId RegId RegId RegId]) => {{ let _ = $op; None }};
+    (impl_op_immediate $op:ident [RegId RegId Imm12]) => { Some(u32::from($op.imm12())) };
+    (impl_op_immediate $op:ident [RegId Imm18]) => { Some(u32::from($op.imm18())) };
+    (impl_op_immediate $op:ident [Imm24]) => { Some(u32::from($op.imm24())) };
+    (impl_op_immediate $op:ident []) => {{ let _ = $op; None }};
+
+    // Parse the operand tokens for a mnemonic into the matching `Instruction` variant.
+    (impl_op_parse $Op:ident $tokens:ident [RegId]) => {{
+        let ra = crate::macros::parse_register($tokens.next())?;
+        crate::macros::parse_end(&mut $tokens)?;
+        Ok(Instruction::from($Op::new(ra)))
+    }};
+    (impl_op_parse $Op:ident $tokens:ident [RegId RegId]) => {{
+        let ra = crate::macros::parse_register($tokens.next())?;
+        let rb = crate::macros::parse_register($tokens.next())?;
+        crate::macros::parse_end(&mut $tokens)?;
+        Ok(Instruction::from($Op::new(ra, rb)))
+    }};
+    (impl_op_parse $Op:ident $tokens:ident [RegId RegId RegId]) => {{
+        let ra = crate::macros::parse_register($tokens.next())?;
+        let rb = crate::macros::parse_register($tokens.next())?;
+        let rc = crate::macros::parse_register($tokens.next())?;
+        crate::macros::parse_end(&mut $tokens)?;
+        Ok(Instruction::from($Op::new(ra, rb, rc)))
+    }};
+    (impl_op_parse $Op:ident $tokens:ident [RegId RegId RegId RegId]) => {{
+        let ra = crate::macros::parse_register($tokens.next())?;
+        let rb = crate::macros::parse_register($tokens.next())?;
+        let rc = crate::macros::parse_register($tokens.next())?;
+        let rd = crate::macros::parse_register($tokens.next())?;
+        crate::macros::parse_end(&mut $tokens)?;
+        Ok(Instruction::from($Op::new(ra, rb, rc, rd)))
+    }};
+    (impl_op_parse $Op:ident $tokens:ident [RegId RegId Imm12]) => {{
+        let ra = crate::macros::parse_register($tokens.next())?;
+        let rb = crate::macros::parse_register($tokens.next())?;
+        let imm = crate::macros::parse_imm12($tokens.next())?;
+        crate::macros::parse_end(&mut $tokens)?;
+        Ok(Instruction::from($Op::new(ra, rb, imm)))
+    }};
+    (impl_op_parse $Op:ident $tokens:ident [RegId Imm18]) => {{
+        let ra = crate::macros::parse_register($tokens.next())?;
+        let imm = crate::macros::parse_imm18($tokens.next())?;
+        crate::macros::parse_end(&mut $tokens)?;
+        Ok(Instruction::from($Op::new(ra, imm)))
+    }};
+    (impl_op_parse $Op:ident $tokens:ident [Imm24]) => {{
+        let imm = crate::macros::parse_imm24($tokens.next())?;
+        crate::macros::parse_end(&mut $tokens)?;
+        Ok(Instruction::from($Op::new(imm)))
+    }};
+    (impl_op_parse $Op:ident $tokens:ident []) => {{
+        crate::macros::parse_end(&mut $tokens)?;
+        Ok(Instruction::from($Op::new()))
+    }};
+
+    // Render a mnemonic and its operands as canonical assembly text.
+    (impl_op_fmt $f:ident, $mnemonic:expr, $op:ident, [RegId]) => {
+        write!($f, "{} {}", $mnemonic, $op.ra())
+    };
+    (impl_op_fmt $f:ident, $mnemonic:expr, $op:ident, [RegId RegId]) => {
+        write!($f, "{} {} {}", $mnemonic, $op.ra(), $op.rb())
+    };
+    (impl_op_fmt $f:ident, $mnemonic:expr, $op:ident, [RegId RegId RegId]) => {
+        write!($f, "{} {} {} {}", $mnemonic, $op.ra(), $op.rb(), $op.rc())
+    };
+    (impl_op_fmt $f:ident, $mnemonic:expr, $op:ident, [RegId RegId RegId RegId]) => {
+        write!($f, "{} {} {} {} {}", $mnemonic, $op.ra(), $op.rb(), $op.rc(), $op.rd())
+    };
+    (impl_op_fmt $f:ident, $mnemonic:expr, $op:ident, [RegId RegId Imm12]) => {
+        write!($f, "{} {} {} {}", $mnemonic, $op.ra(), $op.rb(), $op.imm12())
+    };
+    (impl_op_fmt $f:ident, $mnemonic:expr, $op:ident, [RegId Imm18]) => {
+        write!($f, "{} {} {}", $mnemonic, $op.ra(), $op.imm18())
+    };
+    (impl_op_fmt $f:ident, $mnemonic:expr, $op:ident, [Imm24]) => {
+        write!($f, "{} {}", $mnemonic, $op.imm24())
+    };
+    (impl_op_fmt $f:ident, $mnemonic:expr, $op:ident, []) => {{
+        let _ = $op;
+        write!($f, "{}", $mnemonic)
+    }};
+
+    // Split an op's register operands into (defs, uses), based on its per-field role tokens
+    // and layout. Each role token lines up positionally with a `RegId` field (immediates carry
+    // no role token, since they're never defined or used as registers).
+    (impl_op_roles $op:ident [Def] [RegId]) => {
+        (arrayvec::ArrayVec::from_iter([$op.ra()]), arrayvec::ArrayVec::new())
+    };
+    (impl_op_roles $op:ident [Use] [RegId]) => {
+        (arrayvec::ArrayVec::new(), arrayvec::ArrayVec::from_iter([$op.ra()]))
+    };
+    (impl_op_roles $op:ident [Def Def] [RegId RegId]) => {
+        (arrayvec::ArrayVec::from_iter([$op.ra(), $op.rb()]), arrayvec::ArrayVec::new())
+    };
+    (impl_op_roles $op:ident [Def Use] [RegId RegId]) => {
+        (arrayvec::ArrayVec::from_iter([$op.ra()]), arrayvec::ArrayVec::from_iter([$op.rb()]))
+    };
+    (impl_op_roles $op:ident [Use Def] [RegId RegId]) => {
+        (arrayvec::ArrayVec::from_iter([$op.rb()]), arrayvec::ArrayVec::from_iter([$op.ra()]))
+    };
+    (impl_op_roles $op:ident [Use Use] [RegId RegId]) => {
+        (arrayvec::ArrayVec::new(), arrayvec::ArrayVec::from_iter([$op.ra(), $op.rb()]))
+    };
+    (impl_op_roles $op:ident [Def Def Def] [RegId RegId RegId]) => {
+        (arrayvec::ArrayVec::from_iter([$op.ra(), $op.rb(), $op.rc()]), arrayvec::ArrayVec::new())
+    };
+    (impl_op_roles $op:ident [Def Def Use] [RegId RegId RegId]) => {
+        (arrayvec::ArrayVec::from_iter([$op.ra(), $op.rb()]), arrayvec::ArrayVec::from_iter([$op.rc()]))
+    };
+    (impl_op_roles $op:ident [Def Use Def] [RegId RegId RegId]) => {
+        (arrayvec::ArrayVec::from_iter([$op.ra(), $op.rc()]), arrayvec::ArrayVec::from_iter([$op.rb()]))
+    };
+    (impl_op_roles $op:ident [Def Use Use] [RegId RegId RegId]) => {
+        (arrayvec::ArrayVec::from_iter([$op.ra()]), arrayvec::ArrayVec::from_iter([$op.rb(), $op.rc()]))
+    };
+    (impl_op_roles $op:ident [Use Def Def] [RegId RegId RegId]) => {
+        (arrayvec::ArrayVec::from_iter([$op.rb(), $op.rc()]), arrayvec::ArrayVec::from_iter([$op.ra()]))
+    };
+    (impl_op_roles $op:ident [Use Def Use] [RegId RegId RegId]) => {
+        (arrayvec::ArrayVec::from_iter([$op.rb()]), arrayvec::ArrayVec::from_iter([$op.ra(), $op.rc()]))
+    };
+    (impl_op_roles $op:ident [Use Use Def] [RegId RegId RegId]) => {
+        (arrayvec::ArrayVec::from_iter([$op.rc()]), arrayvec::ArrayVec::from_iter([$op.ra(), $op.rb()]))
+    };
+    (impl_op_roles $op:ident [Use Use Use] [RegId RegId RegId]) => {
+        (arrayvec::ArrayVec::new(), arrayvec::ArrayVec::from_iter([$op.ra(), $op.rb(), $op.rc()]))
+    };
+    (impl_op_roles $op:ident [Def Def Def Def] [RegId RegId RegId RegId]) => {
+        (arrayvec::ArrayVec::from_iter([$op.ra(), $op.rb(), $op.rc(), $op.rd()]), arrayvec::ArrayVec::new())
+    };
+    (impl_op_roles $op:ident [Def Def Def Use] [RegId RegId RegId RegId]) => {
+        (arrayvec::ArrayVec::from_iter([$op.ra(), $op.rb(), $op.rc()]), arrayvec::ArrayVec::from_iter([$op.rd()]))
+    };
+    (impl_op_roles $op:ident [Def Def Use Def] [RegId RegId RegId RegId]) => {
+        (arrayvec::ArrayVec::from_iter([$op.ra(), $op.rb(), $op.rd()]), arrayvec::ArrayVec::from_iter([$op.rc()]))
+    };
+    (impl_op_roles $op:ident [Def Def Use Use] [RegId RegId RegId RegId]) => {
+        (arrayvec::ArrayVec::from_iter([$op.ra(), $op.rb()]), arrayvec::ArrayVec::from_iter([$op.rc(), $op.rd()]))
+    };
+    (impl_op_roles $op:ident [Def Use Def Def] [RegId RegId RegId RegId]) => {
+        (arrayvec::ArrayVec::from_iter([$op.ra(), $op.rc(), $op.rd()]), arrayvec::ArrayVec::from_iter([$op.rb()]))
+    };
+    (impl_op_roles $op:ident [Def Use Def Use] [RegId RegId RegId RegId]) => {
+        (arrayvec::ArrayVec::from_iter([$op.ra(), $op.rc()]), arrayvec::ArrayVec::from_iter([$op.rb(), $op.rd()]))
+    };
+    (impl_op_roles $op:ident [Def Use Use Def] [RegId RegId RegId RegId]) => {
+        (arrayvec::ArrayVec::from_iter([$op.ra(), $op.rd()]), arrayvec::ArrayVec::from_iter([$op.rb(), $op.rc()]))
+    };
+    (impl_op_roles $op:ident [Def Use Use Use] [RegId RegId RegId RegId]) => {
+        (arrayvec::ArrayVec::from_iter([$op.ra()]), arrayvec::ArrayVec::from_iter([$op.rb(), $op.rc(), $op.rd()]))
+    };
+    (impl_op_roles $op:ident [Use Def Def Def] [RegId RegId RegId RegId]) => {
+        (arrayvec::ArrayVec::from_iter([$op.rb(), $op.rc(), $op.rd()]), arrayvec::ArrayVec::from_iter([$op.ra()]))
+    };
+    (impl_op_roles $op:ident [Use Def Def Use] [RegId RegId RegId RegId]) => {
+        (arrayvec::ArrayVec::from_iter([$op.rb(), $op.rc()]), arrayvec::ArrayVec::from_iter([$op.ra(), $op.rd()]))
+    };
+    (impl_op_roles $op:ident [Use Def Use Def] [RegId RegId RegId RegId]) => {
+        (arrayvec::ArrayVec::from_iter([$op.rb(), $op.rd()]), arrayvec::ArrayVec::from_iter([$op.ra(), $op.rc()]))
+    };
+    (impl_op_roles $op:ident [Use Def Use Use] [RegId RegId RegId RegId]) => {
+        (arrayvec::ArrayVec::from_iter([$op.rb()]), arrayvec::ArrayVec::from_iter([$op.ra(), $op.rc(), $op.rd()]))
+    };
+    (impl_op_roles $op:ident [Use Use Def Def] [RegId RegId RegId RegId]) => {
+        (arrayvec::ArrayVec::from_iter([$op.rc(), $op.rd()]), arrayvec::ArrayVec::from_iter([$op.ra(), $op.rb()]))
+    };
+    (impl_op_roles $op:ident [Use Use Def Use] [RegId RegId RegId RegId]) => {
+        (arrayvec::ArrayVec::from_iter([$op.rc()]), arrayvec::ArrayVec::from_iter([$op.ra(), $op.rb(), $op.rd()]))
+    };
+    (impl_op_roles $op:ident [Use Use Use Def] [RegId RegId RegId RegId]) => {
+        (arrayvec::ArrayVec::from_iter([$op.rd()]), arrayvec::ArrayVec::from_iter([$op.ra(), $op.rb(), $op.rc()]))
+    };
+    (impl_op_roles $op:ident [Use Use Use Use] [RegId RegId RegId RegId]) => {
+        (arrayvec::ArrayVec::new(), arrayvec::ArrayVec::from_iter([$op.ra(), $op.rb(), $op.rc(), $op.rd()]))
+    };
+    (impl_op_roles $op:ident [Def Def] [RegId RegId Imm12]) => {
+        (arrayvec::ArrayVec::from_iter([$op.ra(), $op.rb()]), arrayvec::ArrayVec::new())
+    };
+    (impl_op_roles $op:ident [Def Use] [RegId RegId Imm12]) => {
+        (arrayvec::ArrayVec::from_iter([$op.ra()]), arrayvec::ArrayVec::from_iter([$op.rb()]))
+    };
+    (impl_op_roles $op:ident [Use Def] [RegId RegId Imm12]) => {
+        (arrayvec::ArrayVec::from_iter([$op.rb()]), arrayvec::ArrayVec::from_iter([$op.ra()]))
+    };
+    (impl_op_roles $op:ident [Use Use] [RegId RegId Imm12]) => {
+        (arrayvec::ArrayVec::new(), arrayvec::ArrayVec::from_iter([$op.ra(), $op.rb()]))
+    };
+    (impl_op_roles $op:ident [Def] [RegId Imm18]) => {
+        (arrayvec::ArrayVec::from_iter([$op.ra()]), arrayvec::ArrayVec::new())
+    };
+    (impl_op_roles $op:ident [Use] [RegId Imm18]) => {
+        (arrayvec::ArrayVec::new(), arrayvec::ArrayVec::from_iter([$op.ra()]))
+    };
+    (impl_op_roles $op:ident [] [Imm24]) => {{
+        let _ = $op;
+        (arrayvec::ArrayVec::new(), arrayvec::ArrayVec::new())
+    }};
+    (impl_op_roles $op:ident [] []) => {{
+        let _ = $op;
+        (arrayvec::ArrayVec::new(), arrayvec::ArrayVec::new())
+    }};
+
+    // Generate a chainable `InstructionBuilder` method named after the $op.
+    (impl_builder_method $doc:literal $Op:ident $op:ident [RegId]) => {
+        #[doc = $doc]
+        pub fn $op(&mut self, ra: RegId) -> &mut Self {
+            self.push($op(ra));
+            self
+        }
+    };
+    (impl_builder_method $doc:literal $Op:ident $op:ident [RegId RegId]) => {
+        #[doc = $doc]
+        pub fn $op(&mut self, ra: RegId, rb: RegId) -> &mut Self {
+            self.push($op(ra, rb));
+            self
+        }
+    };
+    (impl_builder_method $doc:literal $Op:ident $op:ident [RegId RegId RegId]) => {
+        #[doc = $doc]
+        pub fn $op(&mut self, ra: RegId, rb: RegId, rc: RegId) -> &mut Self {
+            self.push($op(ra, rb, rc));
+            self
+        }
+    };
+    (impl_builder_method $doc:literal $Op:ident $op:ident [RegId RegId RegId RegId]) => {
+        #[doc = $doc]
+        pub fn $op(&mut self, ra: RegId, rb: RegId, rc: RegId, rd: RegId) -> &mut Self {
+            self.push($op(ra, rb, rc, rd));
+            self
+        }
+    };
+    (impl_builder_method $doc:literal $Op:ident $op:ident [RegId RegId Imm12]) => {
+        #[doc = $doc]
+        pub fn $op(&mut self, ra: RegId, rb: RegId, imm: Imm12) -> &mut Self {
+            self.push($op(ra, rb, imm));
+            self
+        }
+    };
+    (impl_builder_method $doc:literal $Op:ident $op:ident [RegId Imm18]) => {
+        #[doc = $doc]
+        pub fn $op(&mut self, ra: RegId, imm: Imm18) -> &mut Self {
+            self.push($op(ra, imm));
+            self
+        }
+    };
+    (impl_builder_method $doc:literal $Op:ident $op:ident [Imm24]) => {
+        #[doc = $doc]
+        pub fn $op(&mut self, imm: Imm24) -> &mut Self {
+            self.push($op(imm));
+            self
+        }
+    };
+    (impl_builder_method $doc:literal $Op:ident $op:ident []) => {
+        #[doc = $doc]
+        pub fn $op(&mut self) -> &mut Self {
+            self.push($op());
+            self
+        }
+    };
+
+    // Implement one chainable method per opcode on `InstructionBuilder`.
+    (impl_builder $($doc:literal $ix:literal $Op:ident $op:ident $group:ident [$($role:ident)*] [$($field:ident)*])*) => {
+        impl crate::builder::InstructionBuilder {
+            $(
+                impl_opcodes!(impl_builder_method $doc $Op $op [$($field)*]);
+            )*
+        }
+    };
+
     // Generate a free function named after the $op for constructing an `Instruction`.
     (impl_op_constructor $doc:literal $Op:ident $op:ident [RegId]) => {
         #[doc = $doc]
@@ -243,8 +639,19 @@ macro_rules! impl_opcodes {
         }
     };
 
+    // A nullary `new()` has no way to pick a sensible set of register/immediate defaults for the
+    // caller, so only opcodes with no operands also get a `Default` impl.
+    (impl_op_default $Op:ident []) => {
+        impl Default for $Op {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+    };
+    (impl_op_default $Op:ident [$($field:ident)*]) => {};
+
     // Implement constructors and accessors for register and immediate values.
-    (impl_op $doc:literal $ix:literal $Op:ident $op:ident [$($field:ident)*] $($rest:tt)*) => {
+    (impl_op $doc:literal $ix:literal $Op:ident $op:ident $group:ident [$($role:ident)*] [$($field:ident)*] $($rest:tt)*) => {
         impl $Op {
             /// The associated 8-bit Opcode value.
             pub const OPCODE: Opcode = Opcode::$Op;
@@ -255,6 +662,7 @@ macro_rules! impl_opcodes {
         }
 
         impl_opcodes!(impl_op_constructor $doc $Op $op [$($field)*]);
+        impl_opcodes!(impl_op_default $Op [$($field)*]);
 
         impl From<$Op> for [u8; 3] {
             fn from($Op(arr): $Op) -> Self {
@@ -285,7 +693,7 @@ macro_rules! impl_opcodes {
     (impl_op) => {};
 
     // Implement `TryFrom<u8>` for `Opcode`.
-    (impl_opcode $($doc:literal $ix:literal $Op:ident $op:ident [$($field:ident)*])*) => {
+    (impl_opcode $($doc:literal $ix:literal $Op:ident $op:ident $group:ident [$($role:ident)*] [$($field:ident)*])*) => {
         impl core::convert::TryFrom<u8> for Opcode {
             type Error = InvalidOpcode;
             fn try_from(u: u8) -> Result<Self, Self::Error> {
@@ -300,7 +708,7 @@ macro_rules! impl_opcodes {
     };
 
     // Implement accessors for register and immediate values.
-    (impl_instruction $($doc:literal $ix:literal $Op:ident $op:ident [$($field:ident)*])*) => {
+    (impl_instruction $($doc:literal $ix:literal $Op:ident $op:ident $group:ident [$($role:ident)*] [$($field:ident)*])*) => {
         impl Instruction {
             /// This instruction's opcode.
             pub fn opcode(&self) -> Opcode {
@@ -311,9 +719,65 @@ macro_rules! impl_opcodes {
                 }
             }
 
-            // TODO:
-            // - pub fn registers(&self) -> Regs
-            // - pub fn immediate(&self) -> Option<u32>
+            /// The register operands carried by this instruction.
+            ///
+            /// Operand positions not used by this instruction's layout are `None`.
+            pub fn registers(&self) -> Regs {
+                match self {
+                    $(
+                        Self::$Op(op) => impl_opcodes!(impl_op_regs op [$($field)*]),
+                    )*
+                }
+            }
+
+            /// The immediate value carried by this instruction, widened to `u32`.
+            ///
+            /// Returns `None` for layouts with no immediate operand.
+            pub fn immediate(&self) -> Option<u32> {
+                match self {
+                    $(
+                        Self::$Op(op) => impl_opcodes!(impl_op_immediate op [$($field)*]),
+                    )*
+                }
+            }
+
+            /// The broad category this instruction's opcode belongs to.
+            pub fn group(&self) -> InstructionGroup {
+                match self {
+                    $(
+                        Self::$Op(_) => InstructionGroup::$group,
+                    )*
+                }
+            }
+
+            /// Whether this instruction transfers control to a fixed or computed target.
+            pub fn is_jump(&self) -> bool {
+                matches!(self.group(), InstructionGroup::Jump)
+            }
+
+            /// Whether this instruction calls into another execution context.
+            pub fn is_call(&self) -> bool {
+                matches!(self.group(), InstructionGroup::Call)
+            }
+
+            /// Whether this instruction ends a basic block, i.e. control does not simply fall
+            /// through to the next instruction.
+            pub fn is_terminator(&self) -> bool {
+                matches!(self.group(), InstructionGroup::Jump | InstructionGroup::Return)
+            }
+
+            /// The register operands this instruction defines (writes) and uses (reads).
+            ///
+            /// Most opcodes write `ra` and read the remaining register operands, but this
+            /// differs for store- and branch-style ops, so each opcode's role pattern is
+            /// declared alongside its layout in the `impl_opcodes!` invocation.
+            pub fn operand_roles(&self) -> (arrayvec::ArrayVec<RegId, 4>, arrayvec::ArrayVec<RegId, 4>) {
+                match self {
+                    $(
+                        Self::$Op(op) => impl_opcodes!(impl_op_roles op [$($role)*] [$($field)*]),
+                    )*
+                }
+            }
         }
 
         impl From<Instruction> for [u8; 4] {
@@ -336,6 +800,31 @@ macro_rules! impl_opcodes {
                 }
             }
         }
+
+        impl core::str::FromStr for Instruction {
+            type Err = ParseError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let mut tokens = s.split_ascii_whitespace();
+                let mnemonic = tokens.next().ok_or(ParseError::Empty)?;
+                match mnemonic {
+                    $(
+                        stringify!($op) => impl_opcodes!(impl_op_parse $Op tokens [$($field)*]),
+                    )*
+                    _ => Err(ParseError::UnknownMnemonic),
+                }
+            }
+        }
+
+        #[cfg(feature = "disasm")]
+        impl core::fmt::Display for Instruction {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                match self {
+                    $(
+                        Self::$Op(op) => impl_opcodes!(impl_op_fmt f, stringify!($op), op, [$($field)*]),
+                    )*
+                }
+            }
+        }
     };
 
     // Entrypoint to the macro, generates structs, methods, opcode enum and instruction enum
@@ -347,5 +836,253 @@ macro_rules! impl_opcodes {
         impl_opcodes!(impl_op $($tts)*);
         impl_opcodes!(impl_opcode $($tts)*);
         impl_opcodes!(impl_instruction $($tts)*);
+        impl_opcodes!(impl_builder $($tts)*);
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_imm12_accepts_max_value() {
+        assert!(parse_imm12(Some("4095")).is_ok());
+    }
+
+    #[test]
+    fn parse_imm12_rejects_overflow() {
+        assert_eq!(parse_imm12(Some("4096")), Err(ParseError::ImmediateOverflow));
+    }
+
+    #[test]
+    fn parse_imm18_rejects_overflow() {
+        assert_eq!(parse_imm18(Some("262144")), Err(ParseError::ImmediateOverflow));
+    }
+
+    #[test]
+    fn parse_imm24_rejects_overflow() {
+        assert_eq!(parse_imm24(Some("16777216")), Err(ParseError::ImmediateOverflow));
+    }
+
+    #[test]
+    fn parse_register_rejects_non_numeric_token() {
+        assert_eq!(parse_register(Some("ra")), Err(ParseError::InvalidOperand));
+    }
+
+    #[test]
+    fn parse_register_rejects_missing_token() {
+        assert_eq!(parse_register(None), Err(ParseError::InvalidOperand));
+    }
+
+    #[test]
+    fn parse_end_rejects_trailing_tokens() {
+        let mut tokens = "extra".split_ascii_whitespace();
+        assert_eq!(parse_end(&mut tokens), Err(ParseError::InvalidOperand));
+    }
+
+    #[test]
+    fn parse_end_accepts_exhausted_tokens() {
+        let mut tokens = "".split_ascii_whitespace();
+        assert_eq!(parse_end(&mut tokens), Ok(()));
+    }
+
+    #[test]
+    fn registers_reports_only_the_fields_the_layout_carries() {
+        let add = crate::Instruction::from(crate::Add::new(RegId::new(1), RegId::new(2), RegId::new(3)));
+        assert_eq!(
+            add.registers(),
+            Regs { ra: Some(RegId::new(1)), rb: Some(RegId::new(2)), rc: Some(RegId::new(3)), rd: None }
+        );
+
+        let ret = crate::Instruction::from(crate::Ret::new(RegId::new(4)));
+        assert_eq!(ret.registers(), Regs { ra: Some(RegId::new(4)), rb: None, rc: None, rd: None });
+
+        let noop = crate::Instruction::from(crate::Noop::new());
+        assert_eq!(noop.registers(), Regs { ra: None, rb: None, rc: None, rd: None });
+    }
+
+    #[test]
+    fn immediate_is_none_for_register_only_layouts() {
+        let add = crate::Instruction::from(crate::Add::new(RegId::new(1), RegId::new(2), RegId::new(3)));
+        assert_eq!(add.immediate(), None);
+    }
+
+    #[test]
+    fn immediate_widens_each_immediate_layout_to_u32() {
+        let addi = crate::Instruction::from(crate::Addi::new(RegId::new(1), RegId::new(2), Imm12::new(7)));
+        assert_eq!(addi.immediate(), Some(7));
+
+        let movi = crate::Instruction::from(crate::Movi::new(RegId::new(1), Imm18::new(99)));
+        assert_eq!(movi.immediate(), Some(99));
+
+        let ji = crate::Instruction::from(crate::Ji::new(Imm24::new(123)));
+        assert_eq!(ji.immediate(), Some(123));
+    }
+
+    #[test]
+    fn group_matches_each_opcodes_declared_category() {
+        let add = crate::Instruction::from(crate::Add::new(RegId::new(1), RegId::new(2), RegId::new(3)));
+        assert_eq!(add.group(), InstructionGroup::Arithmetic);
+
+        let call = crate::Instruction::from(crate::Call::new(
+            RegId::new(1),
+            RegId::new(2),
+            RegId::new(3),
+            RegId::new(4),
+        ));
+        assert_eq!(call.group(), InstructionGroup::Call);
+    }
+
+    #[test]
+    fn is_jump_is_true_only_for_the_jump_group() {
+        let ji = crate::Instruction::from(crate::Ji::new(Imm24::new(0)));
+        assert!(ji.is_jump());
+
+        let call = crate::Instruction::from(crate::Call::new(
+            RegId::new(1),
+            RegId::new(2),
+            RegId::new(3),
+            RegId::new(4),
+        ));
+        assert!(!call.is_jump());
+    }
+
+    #[test]
+    fn is_call_is_true_only_for_the_call_group() {
+        let call = crate::Instruction::from(crate::Call::new(
+            RegId::new(1),
+            RegId::new(2),
+            RegId::new(3),
+            RegId::new(4),
+        ));
+        assert!(call.is_call());
+
+        let ji = crate::Instruction::from(crate::Ji::new(Imm24::new(0)));
+        assert!(!ji.is_call());
+    }
+
+    #[test]
+    fn call_falls_through_and_is_not_a_terminator() {
+        // A `call` transfers control into another contract but always returns to the
+        // instruction after it, so it must not be treated as ending a basic block.
+        let call = crate::Instruction::from(crate::Call::new(
+            RegId::new(1),
+            RegId::new(2),
+            RegId::new(3),
+            RegId::new(4),
+        ));
+        assert!(!call.is_terminator());
+    }
+
+    #[test]
+    fn jumps_and_returns_are_terminators() {
+        let ji = crate::Instruction::from(crate::Ji::new(Imm24::new(0)));
+        assert!(ji.is_terminator());
+
+        let ret = crate::Instruction::from(crate::Ret::new(RegId::new(1)));
+        assert!(ret.is_terminator());
+
+        let add = crate::Instruction::from(crate::Add::new(RegId::new(1), RegId::new(2), RegId::new(3)));
+        assert!(!add.is_terminator());
+    }
+
+    #[test]
+    fn operand_roles_splits_registers_by_their_declared_role() {
+        let add = crate::Instruction::from(crate::Add::new(RegId::new(1), RegId::new(2), RegId::new(3)));
+        let (defs, uses) = add.operand_roles();
+        assert_eq!(&defs[..], [RegId::new(1)]);
+        assert_eq!(&uses[..], [RegId::new(2), RegId::new(3)]);
+    }
+
+    #[test]
+    fn operand_roles_handles_a_store_style_opcode_that_defines_no_registers() {
+        let sb = crate::Instruction::from(crate::Sb::new(RegId::new(1), RegId::new(2), Imm12::new(0)));
+        let (defs, uses) = sb.operand_roles();
+        assert!(defs.is_empty());
+        assert_eq!(&uses[..], [RegId::new(1), RegId::new(2)]);
+    }
+
+    #[test]
+    fn operand_roles_handles_an_opcode_that_defines_two_registers() {
+        let dvm = crate::Instruction::from(crate::Dvm::new(
+            RegId::new(1),
+            RegId::new(2),
+            RegId::new(3),
+            RegId::new(4),
+        ));
+        let (defs, uses) = dvm.operand_roles();
+        assert_eq!(&defs[..], [RegId::new(1), RegId::new(2)]);
+        assert_eq!(&uses[..], [RegId::new(3), RegId::new(4)]);
+    }
+
+    #[test]
+    fn operand_roles_is_empty_for_immediate_only_and_nullary_layouts() {
+        let ji = crate::Instruction::from(crate::Ji::new(Imm24::new(0)));
+        let (defs, uses) = ji.operand_roles();
+        assert!(defs.is_empty());
+        assert!(uses.is_empty());
+
+        let noop = crate::Instruction::from(crate::Noop::new());
+        let (defs, uses) = noop.operand_roles();
+        assert!(defs.is_empty());
+        assert!(uses.is_empty());
+    }
+
+    #[test]
+    fn from_str_dispatches_on_mnemonic() {
+        let inst: crate::Instruction = "add 1 2 3".parse().unwrap();
+        assert_eq!(inst, crate::Instruction::from(crate::Add::new(RegId::new(1), RegId::new(2), RegId::new(3))));
+    }
+
+    #[test]
+    fn from_str_rejects_an_unknown_mnemonic() {
+        let err: ParseError = "frobnicate 1 2".parse::<crate::Instruction>().unwrap_err();
+        assert_eq!(err, ParseError::UnknownMnemonic);
+    }
+
+    #[test]
+    fn from_str_rejects_an_empty_line() {
+        let err: ParseError = "".parse::<crate::Instruction>().unwrap_err();
+        assert_eq!(err, ParseError::Empty);
+    }
+
+    #[test]
+    fn from_str_rejects_the_wrong_operand_arity() {
+        let err: ParseError = "add 1 2".parse::<crate::Instruction>().unwrap_err();
+        assert_eq!(err, ParseError::InvalidOperand);
+    }
+
+    #[test]
+    fn from_str_rejects_an_immediate_that_overflows_its_width() {
+        let err: ParseError = "addi 1 2 4096".parse::<crate::Instruction>().unwrap_err();
+        assert_eq!(err, ParseError::ImmediateOverflow);
+    }
+
+    #[cfg(feature = "disasm")]
+    #[test]
+    fn display_renders_canonical_assembly_text() {
+        use alloc::string::ToString;
+        let add = crate::Instruction::from(crate::Add::new(RegId::new(1), RegId::new(2), RegId::new(3)));
+        assert_eq!(add.to_string(), "add $1 $2 $3");
+
+        let noop = crate::Instruction::from(crate::Noop::new());
+        assert_eq!(noop.to_string(), "noop");
+    }
+
+    #[cfg(feature = "disasm")]
+    #[test]
+    fn parsing_and_formatting_every_opcode_round_trips() {
+        use alloc::string::ToString;
+        use core::str::FromStr;
+
+        for byte in 0u8..=0xff {
+            let Ok(opcode) = crate::Opcode::try_from(byte) else { continue };
+            let ix: u32 = (opcode as u8 as u32) << 24;
+            let bytes = ix.to_be_bytes();
+            let inst = crate::Instruction::try_from(bytes).unwrap();
+            let text = inst.to_string();
+            let reparsed = crate::Instruction::from_str(&text).unwrap();
+            assert_eq!(inst, reparsed);
+        }
+    }
+}
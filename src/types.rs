@@ -0,0 +1,173 @@
+//! Primitive operand types and the raw byte packing/unpacking they're built on.
+
+/// Identifies one of the VM's 64 general-purpose registers.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RegId(u8);
+
+impl RegId {
+    /// The number of bits a register ID occupies in an instruction word.
+    pub const BITS: u32 = 6;
+
+    /// Construct a register ID, masking off any bits beyond the 6-bit range.
+    pub const fn new(id: u8) -> Self {
+        Self(id & 0x3f)
+    }
+
+    /// The raw register index.
+    pub const fn to_u8(self) -> u8 {
+        self.0
+    }
+}
+
+impl core::fmt::Display for RegId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "${}", self.0)
+    }
+}
+
+macro_rules! impl_immediate {
+    ($(#[$doc:meta])* $Imm:ident, $repr:ty, $bits:expr) => {
+        $(#[$doc])*
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub struct $Imm($repr);
+
+        impl $Imm {
+            /// The number of bits this immediate occupies in an instruction word.
+            pub const BITS: u32 = $bits;
+
+            /// The largest value representable in `Self::BITS` bits.
+            pub const MAX: $repr = ((1u32 << $bits) - 1) as $repr;
+
+            /// Construct an immediate, masking off any bits beyond its width.
+            pub const fn new(v: $repr) -> Self {
+                Self(v & Self::MAX)
+            }
+        }
+
+        impl From<$Imm> for u32 {
+            fn from(imm: $Imm) -> Self {
+                imm.0 as u32
+            }
+        }
+
+        impl core::fmt::Display for $Imm {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+    };
+}
+
+impl_immediate!(
+    /// A 12-bit immediate value.
+    Imm12, u16, 12
+);
+impl_immediate!(
+    /// An 18-bit immediate value.
+    Imm18, u32, 18
+);
+impl_immediate!(
+    /// A 24-bit immediate value.
+    Imm24, u32, 24
+);
+
+/// The error returned when a byte does not correspond to a known `Opcode`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct InvalidOpcode;
+
+impl core::fmt::Display for InvalidOpcode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("invalid opcode")
+    }
+}
+
+fn unpack24(bytes: [u8; 3]) -> u32 {
+    (bytes[0] as u32) << 16 | (bytes[1] as u32) << 8 | bytes[2] as u32
+}
+
+fn pack24(bits: u32) -> [u8; 3] {
+    [(bits >> 16) as u8, (bits >> 8) as u8, bits as u8]
+}
+
+pub(crate) fn ra_from_bytes(bytes: [u8; 3]) -> RegId {
+    RegId::new(((unpack24(bytes) >> 18) & 0x3f) as u8)
+}
+
+pub(crate) fn rb_from_bytes(bytes: [u8; 3]) -> RegId {
+    RegId::new(((unpack24(bytes) >> 12) & 0x3f) as u8)
+}
+
+pub(crate) fn rc_from_bytes(bytes: [u8; 3]) -> RegId {
+    RegId::new(((unpack24(bytes) >> 6) & 0x3f) as u8)
+}
+
+pub(crate) fn rd_from_bytes(bytes: [u8; 3]) -> RegId {
+    RegId::new((unpack24(bytes) & 0x3f) as u8)
+}
+
+pub(crate) fn imm12_from_bytes(bytes: [u8; 3]) -> Imm12 {
+    Imm12::new((unpack24(bytes) & 0xfff) as u16)
+}
+
+pub(crate) fn imm18_from_bytes(bytes: [u8; 3]) -> Imm18 {
+    Imm18::new(unpack24(bytes) & 0x3ffff)
+}
+
+pub(crate) fn imm24_from_bytes(bytes: [u8; 3]) -> Imm24 {
+    Imm24::new(unpack24(bytes) & 0xffffff)
+}
+
+pub(crate) fn ra_rb_from_bytes(bytes: [u8; 3]) -> (RegId, RegId) {
+    (ra_from_bytes(bytes), rb_from_bytes(bytes))
+}
+
+pub(crate) fn ra_rb_rc_from_bytes(bytes: [u8; 3]) -> (RegId, RegId, RegId) {
+    (ra_from_bytes(bytes), rb_from_bytes(bytes), rc_from_bytes(bytes))
+}
+
+pub(crate) fn ra_rb_rc_rd_from_bytes(bytes: [u8; 3]) -> (RegId, RegId, RegId, RegId) {
+    (ra_from_bytes(bytes), rb_from_bytes(bytes), rc_from_bytes(bytes), rd_from_bytes(bytes))
+}
+
+pub(crate) fn ra_rb_imm12_from_bytes(bytes: [u8; 3]) -> (RegId, RegId, Imm12) {
+    (ra_from_bytes(bytes), rb_from_bytes(bytes), imm12_from_bytes(bytes))
+}
+
+pub(crate) fn ra_imm18_from_bytes(bytes: [u8; 3]) -> (RegId, Imm18) {
+    (ra_from_bytes(bytes), imm18_from_bytes(bytes))
+}
+
+pub(crate) fn bytes_from_ra(ra: RegId) -> [u8; 3] {
+    pack24((ra.to_u8() as u32) << 18)
+}
+
+pub(crate) fn bytes_from_ra_rb(ra: RegId, rb: RegId) -> [u8; 3] {
+    pack24((ra.to_u8() as u32) << 18 | (rb.to_u8() as u32) << 12)
+}
+
+pub(crate) fn bytes_from_ra_rb_rc(ra: RegId, rb: RegId, rc: RegId) -> [u8; 3] {
+    pack24((ra.to_u8() as u32) << 18 | (rb.to_u8() as u32) << 12 | (rc.to_u8() as u32) << 6)
+}
+
+pub(crate) fn bytes_from_ra_rb_rc_rd(ra: RegId, rb: RegId, rc: RegId, rd: RegId) -> [u8; 3] {
+    pack24(
+        (ra.to_u8() as u32) << 18
+            | (rb.to_u8() as u32) << 12
+            | (rc.to_u8() as u32) << 6
+            | (rd.to_u8() as u32),
+    )
+}
+
+pub(crate) fn bytes_from_ra_rb_imm12(ra: RegId, rb: RegId, imm: Imm12) -> [u8; 3] {
+    pack24((ra.to_u8() as u32) << 18 | (rb.to_u8() as u32) << 12 | u32::from(imm))
+}
+
+pub(crate) fn bytes_from_ra_imm18(ra: RegId, imm: Imm18) -> [u8; 3] {
+    pack24((ra.to_u8() as u32) << 18 | u32::from(imm))
+}
+
+pub(crate) fn bytes_from_imm24(imm: Imm24) -> [u8; 3] {
+    pack24(u32::from(imm))
+}